@@ -1,6 +1,7 @@
 mod sidecar;
 
 use tauri::RunEvent;
+use tauri_plugin_log::{Target, TargetKind};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -10,12 +11,22 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
+        .plugin(
+            tauri_plugin_log::Builder::new()
+                .targets([
+                    Target::new(TargetKind::LogDir { file_name: None }),
+                    Target::new(TargetKind::Stdout),
+                    Target::new(TargetKind::Webview),
+                ])
+                .max_file_size(5_000_000)
+                .build(),
+        )
         .setup(|app| {
             // Start the sidecar on app startup
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
-                if let Err(e) = sidecar::start_sidecar(&app_handle).await {
-                    eprintln!("Failed to start sidecar: {}", e);
+                if let Err(e) = sidecar::start_sidecar(&app_handle, "default").await {
+                    log::error!(target: "sidecar", "Failed to start sidecar: {}", e);
                 }
             });
             Ok(())
@@ -25,14 +36,16 @@ pub fn run() {
             sidecar::stop_backend,
             sidecar::get_backend_url,
             sidecar::check_backend_health,
+            sidecar::list_backends,
+            sidecar::get_sidecar_logs,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|_app_handle, event| {
             if let RunEvent::Exit = event {
-                println!("Application exiting, stopping sidecar...");
-                if let Err(e) = sidecar::stop_sidecar() {
-                    eprintln!("Failed to stop sidecar on exit: {}", e);
+                log::info!(target: "sidecar", "Application exiting, stopping sidecars...");
+                if let Err(e) = sidecar::stop_all_sidecars() {
+                    log::error!(target: "sidecar", "Failed to stop sidecars on exit: {}", e);
                 }
             }
         });
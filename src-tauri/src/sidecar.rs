@@ -1,15 +1,25 @@
 //! Sidecar management for the Python anonymization API.
+//!
+//! Sidecars are tracked in a registry keyed by instance id so several
+//! anonymization workers (e.g. for different documents or languages) can
+//! run side by side without fighting over one process or port.
 
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Mutex;
-use tauri::AppHandle;
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_shell::process::CommandChild;
 use tauri_plugin_shell::ShellExt;
+use tokio::sync::Notify;
 use tokio::time::{sleep, Duration};
 
-/// The port the sidecar API runs on.
-const SIDECAR_PORT: u16 = 14200;
+/// Instance id used when the caller doesn't specify one.
+const DEFAULT_INSTANCE_ID: &str = "default";
+
+/// Number of candidate ports to probe before giving up.
+const PORT_PROBE_ATTEMPTS: u32 = 20;
 
 /// Maximum time to wait for the sidecar to become healthy.
 const HEALTH_CHECK_TIMEOUT_SECS: u64 = 30;
@@ -17,40 +27,220 @@ const HEALTH_CHECK_TIMEOUT_SECS: u64 = 30;
 /// Interval between health check attempts.
 const HEALTH_CHECK_INTERVAL_MS: u64 = 500;
 
-/// Global state for the sidecar process.
-static SIDECAR_RUNNING: AtomicBool = AtomicBool::new(false);
-static SIDECAR_PROCESS: Mutex<Option<CommandChild>> = Mutex::new(None);
+/// Initial delay before the first restart attempt after a crash.
+const RESTART_BASE_DELAY_MS: u64 = 500;
+
+/// Upper bound on the exponential restart backoff.
+const RESTART_MAX_DELAY_MS: u64 = 30_000;
+
+/// Give up restarting after this many consecutive failed attempts.
+const RESTART_MAX_ATTEMPTS: u32 = 10;
+
+/// How long a restarted sidecar must stay healthy before the attempt
+/// counter is reset back to zero.
+const RESTART_STABLE_WINDOW_SECS: u64 = 60;
+
+/// Number of rotated sidecar log files to keep on disk.
+const MAX_LOG_FILES: usize = 5;
+
+/// Default number of tail lines returned by `get_sidecar_logs`.
+const DEFAULT_LOG_TAIL_LINES: usize = 200;
+
+/// All the state for a single running (or most recently started) sidecar.
+/// Mirrors the shell plugin's internal `ChildStore`: a registry of these,
+/// keyed by instance id, replaces the old single set of global statics.
+struct SidecarInstance {
+    process: Mutex<Option<CommandChild>>,
+    running: AtomicBool,
+    port: AtomicU16,
+    shutdown_requested: AtomicBool,
+    restarting: AtomicBool,
+    restart_attempts: AtomicU32,
+    /// Notified when the process crashes again while a supervisor loop for
+    /// this instance is already running, so a loop waiting out its stable
+    /// window can react immediately instead of waiting for the window to
+    /// elapse (see `supervise_restart`). A crash during the backoff delay or
+    /// the health wait notifies with nobody waiting, which leaves a permit
+    /// that the next stable-window wait receives at once.
+    crash_notify: Notify,
+}
+
+impl SidecarInstance {
+    fn new() -> Self {
+        Self {
+            process: Mutex::new(None),
+            running: AtomicBool::new(false),
+            port: AtomicU16::new(0),
+            shutdown_requested: AtomicBool::new(false),
+            restarting: AtomicBool::new(false),
+            restart_attempts: AtomicU32::new(0),
+            crash_notify: Notify::new(),
+        }
+    }
+
+    fn port(&self) -> u16 {
+        self.port.load(Ordering::SeqCst)
+    }
+
+    fn transport_url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port())
+    }
+}
+
+/// Registry of sidecar instances, keyed by instance id.
+static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<SidecarInstance>>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<String, Arc<SidecarInstance>>> {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Get the instance for `id`, creating it if this is the first time it's
+/// referenced.
+fn instance_for(id: &str) -> Result<Arc<SidecarInstance>, String> {
+    let mut registry = registry().lock().map_err(|e| e.to_string())?;
+    Ok(registry
+        .entry(id.to_string())
+        .or_insert_with(|| Arc::new(SidecarInstance::new()))
+        .clone())
+}
+
+/// Get the instance for `id` only if it has already been created.
+fn existing_instance(id: &str) -> Result<Option<Arc<SidecarInstance>>, String> {
+    Ok(registry().lock().map_err(|e| e.to_string())?.get(id).cloned())
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HealthResponse {
     pub status: String,
     pub model_loaded: bool,
     pub version: String,
+    /// Fraction of the spaCy model load completed so far, in `[0.0, 1.0]`.
+    #[serde(default)]
+    pub load_progress: f32,
+    /// Human-readable description of what the sidecar is currently doing
+    /// (e.g. "downloading model", "loading model", "ready").
+    #[serde(default)]
+    pub stage: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BackendStatus {
+    pub id: String,
     pub running: bool,
     pub healthy: bool,
     pub url: String,
+    pub restarting: bool,
+    pub restart_attempts: u32,
+    pub load_progress: f32,
+    pub stage: String,
 }
 
-/// Start the sidecar process.
-pub async fn start_sidecar(app: &AppHandle) -> Result<(), String> {
-    if SIDECAR_RUNNING.load(Ordering::SeqCst) {
+/// Payload of the `sidecar://health-progress` event emitted while waiting
+/// for a sidecar's model to finish loading.
+#[derive(Debug, Clone, Serialize)]
+struct HealthProgress {
+    id: String,
+    load_progress: f32,
+    stage: String,
+}
+
+/// Payload of the `sidecar://crashed` and `sidecar://restored` events.
+#[derive(Debug, Clone, Serialize)]
+struct InstanceEvent {
+    id: String,
+}
+
+/// Payload of the `sidecar://restarting` event.
+#[derive(Debug, Clone, Serialize)]
+struct RestartingEvent {
+    id: String,
+    attempt: u32,
+}
+
+/// Start the sidecar process for `id`.
+pub async fn start_sidecar(app: &AppHandle, id: &str) -> Result<(), String> {
+    let instance = instance_for(id)?;
+    if instance.running.load(Ordering::SeqCst) {
         return Ok(());
     }
 
-    println!("Starting anonymize-api sidecar...");
+    prune_old_logs(app);
+
+    instance.shutdown_requested.store(false, Ordering::SeqCst);
+    spawn_sidecar(app, id, &instance).await?;
+
+    // Wait for the sidecar to become healthy
+    wait_for_health(app, id, &instance).await?;
+
+    log::info!(
+        target: &format!("sidecar::{}", id),
+        "Sidecar started successfully on {}",
+        instance.transport_url()
+    );
+    Ok(())
+}
+
+/// Find a free TCP port by letting the OS assign one, then cross-checking
+/// it against currently listening sockets so a port another process is
+/// already listening on is skipped. The port is not reserved between the
+/// probe and the actual spawn.
+fn find_free_port() -> Result<u16, String> {
+    for _ in 0..PORT_PROBE_ATTEMPTS {
+        let listener = TcpListener::bind(("127.0.0.1", 0))
+            .map_err(|e| format!("Failed to probe for a free port: {}", e))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to read probed port: {}", e))?
+            .port();
+        drop(listener);
+
+        if !is_port_listening(port) {
+            return Ok(port);
+        }
+    }
+
+    Err(format!(
+        "Failed to find a free port after {} attempts",
+        PORT_PROBE_ATTEMPTS
+    ))
+}
+
+/// Check whether some other process is already listening on `port`, using
+/// `netstat2` as a cross-check against the `TcpListener` probe above.
+fn is_port_listening(port: u16) -> bool {
+    use netstat2::{AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+    let sockets = match netstat2::get_sockets_info(AddressFamilyFlags::IPV4, ProtocolFlags::TCP) {
+        Ok(sockets) => sockets,
+        Err(_) => return false,
+    };
 
+    sockets.iter().any(|socket| match &socket.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) => {
+            tcp.local_port == port && tcp.state == netstat2::TcpState::Listen
+        }
+        _ => false,
+    })
+}
+
+/// Spawn the sidecar process for `instance` and hand its output stream off
+/// to a task that forwards logs and supervises crashes.
+async fn spawn_sidecar(
+    app: &AppHandle,
+    id: &str,
+    instance: &Arc<SidecarInstance>,
+) -> Result<(), String> {
     let shell = app.shell();
-    let sidecar = shell
-        .sidecar("anonymize-api")
-        .map_err(|e| format!(
-            "Failed to create sidecar command: {}. \
-            The sidecar binary 'anonymize-api' may be missing from the installation.",
-            e
-        ))?;
+    let mut sidecar = shell.sidecar("anonymize-api").map_err(|e| format!(
+        "Failed to create sidecar command: {}. \
+        The sidecar binary 'anonymize-api' may be missing from the installation.",
+        e
+    ))?;
+
+    let port = find_free_port()?;
+    log::info!(target: &format!("sidecar::{}", id), "Starting anonymize-api sidecar on port {}...", port);
+    sidecar = sidecar.args(["--port", &port.to_string()]);
+    instance.port.store(port, Ordering::SeqCst);
 
     let (mut rx, child) = sidecar.spawn().map_err(|e| {
         format!(
@@ -65,75 +255,225 @@ pub async fn start_sidecar(app: &AppHandle) -> Result<(), String> {
 
     // Store the child process
     {
-        let mut process = SIDECAR_PROCESS.lock().map_err(|e| e.to_string())?;
+        let mut process = instance.process.lock().map_err(|e| e.to_string())?;
         *process = Some(child);
     }
 
-    SIDECAR_RUNNING.store(true, Ordering::SeqCst);
+    instance.running.store(true, Ordering::SeqCst);
 
-    // Spawn a task to handle sidecar output
+    // Spawn a task to handle sidecar output and supervise crashes
+    let app_handle = app.clone();
+    let instance_id = id.to_string();
+    let instance_handle = instance.clone();
     tauri::async_runtime::spawn(async move {
         use tauri_plugin_shell::process::CommandEvent;
+        let log_target = format!("sidecar::{}", instance_id);
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line) => {
-                    println!("[sidecar] {}", String::from_utf8_lossy(&line));
+                    log::info!(target: &log_target, "{}", String::from_utf8_lossy(&line));
                 }
                 CommandEvent::Stderr(line) => {
-                    eprintln!("[sidecar] {}", String::from_utf8_lossy(&line));
+                    log::warn!(target: &log_target, "{}", String::from_utf8_lossy(&line));
                 }
                 CommandEvent::Terminated(payload) => {
-                    println!("[sidecar] Terminated with code: {:?}", payload.code);
-                    SIDECAR_RUNNING.store(false, Ordering::SeqCst);
+                    log::warn!(target: &log_target, "Terminated with code: {:?}", payload.code);
+                    instance_handle.running.store(false, Ordering::SeqCst);
+
+                    if instance_handle.shutdown_requested.load(Ordering::SeqCst) {
+                        // Intentional stop, nothing to supervise.
+                    } else {
+                        let _ = app_handle.emit(
+                            "sidecar://crashed",
+                            InstanceEvent { id: instance_id.clone() },
+                        );
+                        supervise_restart(app_handle.clone(), instance_id.clone(), instance_handle.clone())
+                            .await;
+                    }
                     break;
                 }
                 CommandEvent::Error(e) => {
-                    eprintln!("[sidecar] Error: {}", e);
+                    log::error!(target: &log_target, "{}", e);
                 }
                 _ => {}
             }
         }
     });
 
-    // Wait for the sidecar to become healthy
-    wait_for_health().await?;
-
-    println!("Sidecar started successfully on port {}", SIDECAR_PORT);
     Ok(())
 }
 
-/// Stop the sidecar process.
-pub fn stop_sidecar() -> Result<(), String> {
-    if !SIDECAR_RUNNING.load(Ordering::SeqCst) {
+/// Restart a sidecar instance after an unexpected crash, backing off
+/// exponentially between attempts until it comes back healthy or the
+/// attempt budget is exhausted.
+async fn supervise_restart(app: AppHandle, id: String, instance: Arc<SidecarInstance>) {
+    if instance
+        .restarting
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        // A supervisor loop for this instance is already running (e.g. the
+        // process it just respawned crashed again before the stable window
+        // elapsed). Wake it so it reacts immediately instead of waiting out
+        // the rest of its current stable-window sleep.
+        instance.crash_notify.notify_one();
+        return;
+    }
+
+    let log_target = format!("sidecar::{}", id);
+
+    loop {
+        if instance.shutdown_requested.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let attempt = instance.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt > RESTART_MAX_ATTEMPTS {
+            log::error!(
+                target: &log_target,
+                "Giving up after {} restart attempts",
+                RESTART_MAX_ATTEMPTS
+            );
+            instance.restart_attempts.store(0, Ordering::SeqCst);
+            break;
+        }
+
+        let delay = restart_backoff_delay(attempt);
+        log::info!(
+            target: &log_target,
+            "Restarting in {:?} (attempt {}/{})",
+            delay, attempt, RESTART_MAX_ATTEMPTS
+        );
+        let _ = app.emit("sidecar://restarting", RestartingEvent { id: id.clone(), attempt });
+        sleep(delay).await;
+
+        if instance.shutdown_requested.load(Ordering::SeqCst) {
+            // Stopped while waiting out the backoff delay; don't undo it.
+            break;
+        }
+
+        if let Err(e) = spawn_sidecar(&app, &id, &instance).await {
+            log::error!(target: &log_target, "Restart attempt {} failed to spawn: {}", attempt, e);
+            continue;
+        }
+
+        match wait_for_health(&app, &id, &instance).await {
+            Ok(()) => {
+                // Only treat the restart as successful if it stays up for
+                // the stable window; otherwise keep counting attempts. Bail
+                // out of the wait early if it crashes again in the
+                // meantime, instead of leaving it unsupervised until the
+                // window elapses. A notify permit can be left over from an
+                // earlier, unrelated crash (see `crash_notify`'s doc
+                // comment), so a wake while the process is still running is
+                // treated as stale and the remaining wait resumes rather
+                // than being cut short.
+                let mut remaining = Duration::from_secs(RESTART_STABLE_WINDOW_SECS);
+                loop {
+                    let waited_from = std::time::Instant::now();
+                    tokio::select! {
+                        _ = sleep(remaining) => break,
+                        _ = instance.crash_notify.notified() => {
+                            if !instance.running.load(Ordering::SeqCst) {
+                                log::warn!(target: &log_target, "Crashed again during the stable window");
+                                break;
+                            }
+                            remaining = remaining.saturating_sub(waited_from.elapsed());
+                            if remaining.is_zero() {
+                                break;
+                            }
+                        }
+                    }
+                }
+                if instance.running.load(Ordering::SeqCst) {
+                    log::info!(target: &log_target, "Restart stable, resetting attempt counter");
+                    instance.restart_attempts.store(0, Ordering::SeqCst);
+                    let _ = app.emit("sidecar://restored", InstanceEvent { id: id.clone() });
+                    break;
+                }
+            }
+            Err(e) => {
+                log::error!(target: &log_target, "Restarted process never became healthy: {}", e);
+            }
+        }
+    }
+
+    instance.restarting.store(false, Ordering::SeqCst);
+}
+
+/// Compute the exponential backoff delay for a given restart attempt
+/// (1-indexed), capped at `RESTART_MAX_DELAY_MS`.
+fn restart_backoff_delay(attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.saturating_sub(1).min(10);
+    let ms = RESTART_BASE_DELAY_MS.saturating_mul(factor).min(RESTART_MAX_DELAY_MS);
+    Duration::from_millis(ms)
+}
+
+/// Stop the sidecar process for `id`.
+pub fn stop_sidecar(id: &str) -> Result<(), String> {
+    let Some(instance) = existing_instance(id)? else {
+        return Ok(());
+    };
+
+    instance.shutdown_requested.store(true, Ordering::SeqCst);
+
+    if !instance.running.load(Ordering::SeqCst) {
         return Ok(());
     }
 
-    println!("Stopping sidecar...");
+    let log_target = format!("sidecar::{}", id);
+    log::info!(target: &log_target, "Stopping sidecar...");
 
-    let mut process = SIDECAR_PROCESS.lock().map_err(|e| e.to_string())?;
+    let mut process = instance.process.lock().map_err(|e| e.to_string())?;
     if let Some(child) = process.take() {
         child.kill().map_err(|e| format!("Failed to kill sidecar: {}", e))?;
     }
 
-    SIDECAR_RUNNING.store(false, Ordering::SeqCst);
-    println!("Sidecar stopped");
+    instance.running.store(false, Ordering::SeqCst);
+    log::info!(target: &log_target, "Sidecar stopped");
+    Ok(())
+}
+
+/// Stop every running sidecar instance, used when the app exits.
+pub fn stop_all_sidecars() -> Result<(), String> {
+    let ids: Vec<String> = registry().lock().map_err(|e| e.to_string())?.keys().cloned().collect();
+    for id in ids {
+        stop_sidecar(&id)?;
+    }
     Ok(())
 }
 
-/// Wait for the sidecar to become healthy.
-async fn wait_for_health() -> Result<(), String> {
-    let url = format!("http://127.0.0.1:{}/health", SIDECAR_PORT);
+/// Wait for `instance`'s sidecar to become healthy.
+async fn wait_for_health(app: &AppHandle, id: &str, instance: &SidecarInstance) -> Result<(), String> {
+    let log_target = format!("sidecar::{}", id);
     let start = std::time::Instant::now();
     let timeout = Duration::from_secs(HEALTH_CHECK_TIMEOUT_SECS);
 
     while start.elapsed() < timeout {
-        match check_health_internal(&url).await {
+        match check_health_internal(instance).await {
             Ok(health) if health.model_loaded => {
-                println!("Sidecar is healthy (version: {})", health.version);
+                log::info!(target: &log_target, "Sidecar is healthy (version: {})", health.version);
+                let _ = app.emit(
+                    "sidecar://health-progress",
+                    HealthProgress { id: id.to_string(), load_progress: 1.0, stage: health.stage },
+                );
                 return Ok(());
             }
-            Ok(_) => {
-                println!("Sidecar responding but model not yet loaded...");
+            Ok(health) => {
+                log::info!(
+                    target: &log_target,
+                    "Responding but model not yet loaded... ({} {:.0}%)",
+                    health.stage,
+                    health.load_progress * 100.0
+                );
+                let _ = app.emit(
+                    "sidecar://health-progress",
+                    HealthProgress {
+                        id: id.to_string(),
+                        load_progress: health.load_progress,
+                        stage: health.stage,
+                    },
+                );
             }
             Err(_) => {
                 // Sidecar not yet responding
@@ -142,92 +482,186 @@ async fn wait_for_health() -> Result<(), String> {
         sleep(Duration::from_millis(HEALTH_CHECK_INTERVAL_MS)).await;
     }
 
-    Err(format!(
+    let message = format!(
         "Backend service failed to start within {} seconds. \
         The service may have crashed during startup. \
         Check if port {} is already in use by another application, \
         or if there are missing dependencies (Python runtime, spaCy model).",
         HEALTH_CHECK_TIMEOUT_SECS,
-        SIDECAR_PORT
-    ))
+        instance.port()
+    );
+    log::error!(target: &log_target, "{}", message);
+    Err(message)
 }
 
-/// Internal health check using reqwest-like functionality.
-async fn check_health_internal(_url: &str) -> Result<HealthResponse, String> {
-    // Use a simple TCP connection and HTTP request
-    use std::io::{Read, Write};
-    use std::net::TcpStream;
-    use std::time::Duration;
-
-    let addr = format!("127.0.0.1:{}", SIDECAR_PORT);
-    let mut stream = TcpStream::connect_timeout(
-        &addr.parse().map_err(|e| format!("Invalid address: {}", e))?,
-        Duration::from_secs(2),
-    )
-    .map_err(|e| format!("Connection failed: {}", e))?;
+/// Dial the sidecar's TCP port over a real HTTP client, so keep-alive,
+/// chunked encoding, and multi-packet bodies are handled correctly.
+async fn check_health_internal(instance: &SidecarInstance) -> Result<HealthResponse, String> {
+    let url = format!("http://127.0.0.1:{}/health", instance.port());
+    reqwest::Client::new()
+        .get(&url)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .map_err(|e| format!("Connection failed: {}", e))?
+        .json::<HealthResponse>()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
 
-    stream
-        .set_read_timeout(Some(Duration::from_secs(5)))
-        .map_err(|e| format!("Failed to set timeout: {}", e))?;
+/// Delete the oldest rotated sidecar log files beyond `MAX_LOG_FILES` so
+/// logging doesn't grow the app's log directory unbounded.
+fn prune_old_logs(app: &AppHandle) {
+    let Ok(dir) = app.path().app_log_dir() else {
+        return;
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return;
+    };
+
+    let mut logs: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .collect();
+
+    logs.sort_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok());
+
+    while logs.len() > MAX_LOG_FILES {
+        let oldest = logs.remove(0);
+        let _ = std::fs::remove_file(oldest.path());
+    }
+}
 
-    let request = format!(
-        "GET /health HTTP/1.1\r\nHost: 127.0.0.1:{}\r\nConnection: close\r\n\r\n",
-        SIDECAR_PORT
-    );
+fn current_status(id: &str, instance: &SidecarInstance, health: Option<&HealthResponse>) -> BackendStatus {
+    BackendStatus {
+        id: id.to_string(),
+        running: instance.running.load(Ordering::SeqCst),
+        healthy: health.map(|h| h.model_loaded).unwrap_or(false),
+        url: instance.transport_url(),
+        restarting: instance.restarting.load(Ordering::SeqCst),
+        restart_attempts: instance.restart_attempts.load(Ordering::SeqCst),
+        load_progress: health.map(|h| h.load_progress).unwrap_or(0.0),
+        stage: health.map(|h| h.stage.clone()).unwrap_or_else(|| "unknown".to_string()),
+    }
+}
 
-    stream
-        .write_all(request.as_bytes())
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+fn resolve_instance_id(instance_id: Option<String>) -> String {
+    instance_id.unwrap_or_else(|| DEFAULT_INSTANCE_ID.to_string())
+}
 
-    let mut response = String::new();
-    stream
-        .read_to_string(&mut response)
-        .map_err(|e| format!("Failed to read response: {}", e))?;
+/// Status for an id that has never been started, without registering it in
+/// the registry — used so a typo'd or speculative id passed to a read-only
+/// query doesn't permanently show up in `list_backends`.
+fn not_started_status(id: &str) -> BackendStatus {
+    BackendStatus {
+        id: id.to_string(),
+        running: false,
+        healthy: false,
+        url: "<not started>".to_string(),
+        restarting: false,
+        restart_attempts: 0,
+        load_progress: 0.0,
+        stage: "not started".to_string(),
+    }
+}
 
-    // Parse the response body (skip headers)
-    let body = response
-        .split("\r\n\r\n")
-        .nth(1)
-        .ok_or("Invalid HTTP response")?;
+// Tauri commands
 
-    serde_json::from_str(body).map_err(|e| format!("Failed to parse response: {}", e))
+#[tauri::command]
+pub async fn start_backend(app: AppHandle, instance_id: Option<String>) -> Result<BackendStatus, String> {
+    let id = resolve_instance_id(instance_id);
+    start_sidecar(&app, &id).await?;
+    let instance = instance_for(&id)?;
+    let health = check_health_internal(&instance).await.ok();
+    Ok(current_status(&id, &instance, health.as_ref()))
 }
 
-// Tauri commands
+#[tauri::command]
+pub fn stop_backend(instance_id: Option<String>) -> Result<(), String> {
+    stop_sidecar(&resolve_instance_id(instance_id))
+}
 
+/// Look up instance `instance_id`'s URL without registering it — an id
+/// that was never started has no URL to report.
 #[tauri::command]
-pub async fn start_backend(app: AppHandle) -> Result<BackendStatus, String> {
-    start_sidecar(&app).await?;
-    Ok(BackendStatus {
-        running: true,
-        healthy: true,
-        url: format!("http://127.0.0.1:{}", SIDECAR_PORT),
-    })
+pub fn get_backend_url(instance_id: Option<String>) -> Result<String, String> {
+    let id = resolve_instance_id(instance_id);
+    match existing_instance(&id)? {
+        Some(instance) => Ok(instance.transport_url()),
+        None => Ok("<not started>".to_string()),
+    }
 }
 
+/// Check instance `instance_id`'s health without registering it — an id
+/// that was never started is reported as not running rather than being
+/// added to the registry.
 #[tauri::command]
-pub fn stop_backend() -> Result<(), String> {
-    stop_sidecar()
+pub async fn check_backend_health(instance_id: Option<String>) -> Result<BackendStatus, String> {
+    let id = resolve_instance_id(instance_id);
+    match existing_instance(&id)? {
+        Some(instance) => {
+            let health = check_health_internal(&instance).await.ok();
+            Ok(current_status(&id, &instance, health.as_ref()))
+        }
+        None => Ok(not_started_status(&id)),
+    }
 }
 
+/// Status of every sidecar instance that has been started this session.
 #[tauri::command]
-pub fn get_backend_url() -> String {
-    format!("http://127.0.0.1:{}", SIDECAR_PORT)
+pub async fn list_backends() -> Result<Vec<BackendStatus>, String> {
+    let ids: Vec<String> = registry().lock().map_err(|e| e.to_string())?.keys().cloned().collect();
+
+    let mut statuses = Vec::with_capacity(ids.len());
+    for id in ids {
+        let instance = instance_for(&id)?;
+        let health = check_health_internal(&instance).await.ok();
+        statuses.push(current_status(&id, &instance, health.as_ref()));
+    }
+    Ok(statuses)
 }
 
+/// Return the tail of the current sidecar log file so the frontend can
+/// surface recent backend output (e.g. in a crash error dialog). Every
+/// sidecar diagnostic is tagged with a `sidecar::<instance id>` target (see
+/// `spawn_sidecar` and the other instance-scoped log calls), so passing
+/// `instance_id` filters the tail down to just that instance's lines;
+/// omitting it returns the combined tail across every instance, since they
+/// all share one rotating log file.
 #[tauri::command]
-pub async fn check_backend_health() -> Result<BackendStatus, String> {
-    let url = format!("http://127.0.0.1:{}/health", SIDECAR_PORT);
-    match check_health_internal(&url).await {
-        Ok(health) => Ok(BackendStatus {
-            running: SIDECAR_RUNNING.load(Ordering::SeqCst),
-            healthy: health.model_loaded,
-            url: format!("http://127.0.0.1:{}", SIDECAR_PORT),
-        }),
-        Err(_) => Ok(BackendStatus {
-            running: SIDECAR_RUNNING.load(Ordering::SeqCst),
-            healthy: false,
-            url: format!("http://127.0.0.1:{}", SIDECAR_PORT),
+pub fn get_sidecar_logs(
+    app: AppHandle,
+    max_lines: Option<usize>,
+    instance_id: Option<String>,
+) -> Result<String, String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to resolve log dir: {}", e))?;
+
+    let log_file = log_dir.join(format!("{}.log", app.package_info().name));
+    let contents = std::fs::read_to_string(&log_file)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    let tag = instance_id.map(|id| format!("sidecar::{}", id));
+    let matches_instance = |line: &&str| match &tag {
+        // Require a non-id character right after the match so id "1" isn't
+        // fooled by a line actually tagged "sidecar::10".
+        Some(tag) => line.match_indices(tag.as_str()).any(|(start, _)| {
+            !line[start + tag.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-')
         }),
-    }
+        None => true,
+    };
+
+    let max_lines = max_lines.unwrap_or(DEFAULT_LOG_TAIL_LINES);
+    let tail: Vec<&str> = contents
+        .lines()
+        .rev()
+        .filter(matches_instance)
+        .take(max_lines)
+        .collect();
+    Ok(tail.into_iter().rev().collect::<Vec<_>>().join("\n"))
 }